@@ -20,6 +20,9 @@ pub struct HttpsConnector<T> {
     force_https: bool,
     http: T,
     tls: TlsConnector,
+    alpn_protocols: Vec<Vec<u8>>,
+    override_dnsname: Option<String>,
+    unix_socket: Option<std::path::PathBuf>,
 }
 
 impl HttpsConnector<HttpConnector> {
@@ -71,6 +74,62 @@ impl<T> HttpsConnector<T> {
         self.force_https = enable;
     }
 
+    /// The ALPN protocols this connector requests, in preference order.
+    pub fn alpn_protocols(&self) -> &[Vec<u8>] {
+        &self.alpn_protocols
+    }
+
+    /// Request ALPN protocols to negotiate during the TLS handshake.
+    ///
+    /// The protocols are offered in preference order, e.g.
+    /// `vec![b"h2".to_vec(), b"http/1.1".to_vec()]` to prefer HTTP/2 and fall
+    /// back to HTTP/1.1. The negotiated protocol is available after the
+    /// handshake via [`MaybeHttpsStream::negotiated_alpn`].
+    ///
+    /// # Warning
+    ///
+    /// This rebuilds the underlying `TlsConnector` from a fresh builder, which
+    /// discards any TLS configuration already in place (custom root
+    /// certificates, `danger_*` overrides, or a connector supplied via
+    /// `From`). The connector has no method to configure trust afterwards; to
+    /// combine ALPN with custom trust settings, use
+    /// [`HttpsConnectorBuilder::alpn_protocols`] to configure everything in one
+    /// chain. Any error creating the TLS context is surfaced rather than
+    /// panicking.
+    pub fn set_alpn_protocols(&mut self, protos: Vec<Vec<u8>>) -> Result<(), BoxError> {
+        let strs = protos
+            .iter()
+            .map(|p| std::str::from_utf8(p))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tls = native_tls::TlsConnector::builder()
+            .request_alpns(&strs)
+            .build()?;
+        self.tls = tls.into();
+        self.alpn_protocols = protos;
+        Ok(())
+    }
+
+    /// Override the hostname used for the TLS handshake.
+    ///
+    /// By default the name drives SNI and certificate verification is taken
+    /// from the `Uri` being connected to. Setting an override pins the
+    /// handshake to a fixed name, which is useful when connecting to an
+    /// address (e.g. a load balancer IP) that differs from the name the
+    /// certificate is issued for.
+    pub fn set_override_dnsname(&mut self, dnsname: Option<String>) {
+        self.override_dnsname = dnsname;
+    }
+
+    /// Route every request over a Unix domain socket instead of the network.
+    ///
+    /// When set, `call` connects to the given socket path and skips TLS,
+    /// regardless of the `Uri` scheme. Because the transport is local, this
+    /// also bypasses a [`https_only(true)`](Self::https_only) guarantee — the
+    /// connection is plaintext over the socket.
+    pub fn set_unix_socket(&mut self, path: Option<std::path::PathBuf>) {
+        self.unix_socket = path;
+    }
+
     /// With connector constructor
     ///
     /// # Panics
@@ -92,12 +151,137 @@ impl<T> HttpsConnector<T> {
     }
 }
 
+/// A builder for [`HttpsConnector`].
+///
+/// Unlike [`HttpsConnector::new`], the connector is configured through a
+/// fluent chain and the terminal [`build`](HttpsConnectorBuilder::build)
+/// returns a `Result` instead of panicking when the TLS context cannot be
+/// created. The builder has no Unix domain socket option; a connector it
+/// builds connects over the network until
+/// [`HttpsConnector::set_unix_socket`] is called on it.
+pub struct HttpsConnectorBuilder<T> {
+    http: T,
+    force_https: bool,
+    alpn_protocols: Vec<Vec<u8>>,
+    override_dnsname: Option<String>,
+    accept_invalid_certs: bool,
+    accept_invalid_hostnames: bool,
+    root_certificates: Vec<native_tls::Certificate>,
+}
+
+impl HttpsConnectorBuilder<HttpConnector> {
+    /// Start a builder using hyper's default `HttpConnector`.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        Self::with_connector(http)
+    }
+}
+
+impl Default for HttpsConnectorBuilder<HttpConnector> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> HttpsConnectorBuilder<T> {
+    /// Start a builder using a custom inner connector `T`.
+    pub fn with_connector(http: T) -> Self {
+        HttpsConnectorBuilder {
+            http,
+            force_https: false,
+            alpn_protocols: Vec::new(),
+            override_dnsname: None,
+            accept_invalid_certs: false,
+            accept_invalid_hostnames: false,
+            root_certificates: Vec::new(),
+        }
+    }
+
+    /// Force the use of HTTPS when connecting.
+    #[must_use]
+    pub fn https_only(mut self, enable: bool) -> Self {
+        self.force_https = enable;
+        self
+    }
+
+    /// Request ALPN protocols to negotiate during the TLS handshake.
+    #[must_use]
+    pub fn alpn_protocols(mut self, protos: Vec<Vec<u8>>) -> Self {
+        self.alpn_protocols = protos;
+        self
+    }
+
+    /// Override the hostname used for SNI and certificate verification.
+    #[must_use]
+    pub fn override_dnsname(mut self, dnsname: impl Into<String>) -> Self {
+        self.override_dnsname = Some(dnsname.into());
+        self
+    }
+
+    /// Add a custom root certificate to the trust store.
+    ///
+    /// This is needed to verify peers that present certificates issued by a
+    /// private PKI not present in the system trust store.
+    #[must_use]
+    pub fn add_root_certificate(mut self, cert: native_tls::Certificate) -> Self {
+        self.root_certificates.push(cert);
+        self
+    }
+
+    /// Accept invalid certificates. This is dangerous and should only be used
+    /// in development against self-signed certificates.
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, enable: bool) -> Self {
+        self.accept_invalid_certs = enable;
+        self
+    }
+
+    /// Accept certificates whose hostname does not match. This is dangerous and
+    /// should only be used in development.
+    #[must_use]
+    pub fn danger_accept_invalid_hostnames(mut self, enable: bool) -> Self {
+        self.accept_invalid_hostnames = enable;
+        self
+    }
+
+    /// Build the `HttpsConnector`, returning an error if the TLS context could
+    /// not be created.
+    pub fn build(self) -> Result<HttpsConnector<T>, BoxError> {
+        let mut builder = native_tls::TlsConnector::builder();
+        if !self.alpn_protocols.is_empty() {
+            let strs = self
+                .alpn_protocols
+                .iter()
+                .map(|p| std::str::from_utf8(p))
+                .collect::<Result<Vec<_>, _>>()?;
+            builder.request_alpns(&strs);
+        }
+        for cert in self.root_certificates {
+            builder.add_root_certificate(cert);
+        }
+        builder.danger_accept_invalid_certs(self.accept_invalid_certs);
+        builder.danger_accept_invalid_hostnames(self.accept_invalid_hostnames);
+        let tls = builder.build()?;
+
+        let mut connector = HttpsConnector::from((self.http, tls.into()));
+        connector.force_https = self.force_https;
+        connector.alpn_protocols = self.alpn_protocols;
+        connector.override_dnsname = self.override_dnsname;
+        Ok(connector)
+    }
+}
+
 impl<T> From<(T, TlsConnector)> for HttpsConnector<T> {
     fn from(args: (T, TlsConnector)) -> HttpsConnector<T> {
         HttpsConnector {
             force_https: false,
             http: args.0,
             tls: args.1,
+            alpn_protocols: Vec::new(),
+            override_dnsname: None,
+            unix_socket: None,
         }
     }
 }
@@ -131,17 +315,33 @@ where
     }
 
     fn call(&mut self, dst: Uri) -> Self::Future {
+        // A configured socket path routes over a local Unix domain socket and
+        // bypasses TLS (and the `force_https` check below) entirely. We do not
+        // infer the path from a `unix://` URI: UDS clients encode the socket
+        // location in the authority, not the path, so the URI path belongs to
+        // the request line rather than the socket.
+        if let Some(path) = self.unix_socket.clone() {
+            let fut = async move {
+                let unix = tokio::net::UnixStream::connect(path).await?;
+                Ok(MaybeHttpsStream::Unix(TokioIo::new(unix, None)))
+            };
+            return HttpsConnecting(Box::pin(fut));
+        }
+
         let is_https = dst.scheme_str() == Some("https");
         // Early abort if HTTPS is forced but can't be used
         if !is_https && self.force_https {
             return err(ForceHttpsButUriNotHttps.into());
         }
 
-        let host = dst
-            .host()
-            .unwrap_or("")
-            .trim_matches(|c| c == '[' || c == ']')
-            .to_owned();
+        let host = match &self.override_dnsname {
+            Some(name) => name.clone(),
+            None => dst
+                .host()
+                .unwrap_or("")
+                .trim_matches(|c| c == '[' || c == ']')
+                .to_owned(),
+        };
         let connecting = self.http.call(dst);
 
         let tls_connector = self.tls.clone();
@@ -155,6 +355,12 @@ where
                 let tls_start = std::time::Instant::now();
                 let tls_stream = tls_connector.connect(&host, stream).await?;
                 let tls_end = std::time::Instant::now();
+                let negotiated_alpn = tls_stream.get_ref().negotiated_alpn()?;
+                // Capture the negotiated ALPN protocol for telemetry. The
+                // protocol version and cipher suite the request also asked for
+                // are not exposed by `native-tls`'s public API, so they are not
+                // recorded on this backend.
+                let tls_alpn = negotiated_alpn.clone();
                 let tls = TokioIo::new(
                     tls_stream,
                     stats.map(|s| ConnectionStats {
@@ -165,9 +371,10 @@ where
                         connect_end: s.connect_end,
                         tls_connect_start: Some(tls_start),
                         tls_connect_end: Some(tls_end),
+                        tls_alpn,
                     }),
                 );
-                MaybeHttpsStream::Https(tls)
+                MaybeHttpsStream::Https(tls, negotiated_alpn)
             } else {
                 MaybeHttpsStream::Http(tcp)
             };